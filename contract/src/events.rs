@@ -0,0 +1,49 @@
+use near_sdk::{
+    env,
+    serde_json::{json, Value},
+    AccountId,
+};
+
+use crate::traits::ProposalId;
+
+/// NEP-297 standard identifier for the vote-lifecycle events emitted by this
+/// contract.
+pub const EVENT_STANDARD: &str = "votron";
+pub const EVENT_VERSION: &str = "1.0.0";
+
+fn emit(event: &str, data: Value) {
+    env::log_str(&format!(
+        "EVENT_JSON:{}",
+        json!({
+            "standard": EVENT_STANDARD,
+            "version": EVENT_VERSION,
+            "event": event,
+            "data": [data],
+        })
+    ));
+}
+
+/// Emitted optimistically when a vote promise is dispatched.
+pub fn vote_submitted(proposal_id: ProposalId, vote: u8, voter: &AccountId) {
+    emit(
+        "vote_submitted",
+        json!({ "proposal_id": proposal_id, "vote": vote, "voter": voter }),
+    );
+}
+
+/// Emitted once the cross-contract call resolves successfully.
+pub fn vote_confirmed(proposal_id: ProposalId, vote: u8, voter: &AccountId) {
+    emit(
+        "vote_confirmed",
+        json!({ "proposal_id": proposal_id, "vote": vote, "voter": voter }),
+    );
+}
+
+/// Emitted when the cross-contract call bounces, or when a batch item is
+/// rejected before dispatch, carrying the failure detail.
+pub fn vote_failed(proposal_id: ProposalId, vote: u8, voter: &AccountId, error: &str) {
+    emit(
+        "vote_failed",
+        json!({ "proposal_id": proposal_id, "vote": vote, "voter": voter, "error": error }),
+    );
+}
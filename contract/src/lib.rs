@@ -1,18 +1,70 @@
 use near_sdk::{
     env::{self},
+    json_types::{Base64VecU8, U128},
     near, require,
     store::{IterableMap, IterableSet},
     AccountId, Gas, NearToken, PanicOnDefault, Promise, PromiseError,
 };
 
+mod events;
 mod traits;
-use traits::{ext_self, ext_voting, MerkleProof, ProposalId, SelfCallbacks, VAccount};
+use near_sdk::serde_json::Value;
+use traits::{
+    ext_self, ext_voting, AttestationQuote, MerkleProof, ProposalId, ProxyVoteArgs, SelfCallbacks,
+    VAccount,
+};
+
+/// Lowercase hex encoding of a byte slice, used to render attestation
+/// measurements for comparison against approved codehashes.
+fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push_str(&format!("{:02x}", b));
+    }
+    out
+}
+
+/// Parse a yocto-denominated amount out of a JSON `Value`, accepting either a
+/// decimal string (the usual encoding for large NEAR balances) or a number.
+fn parse_amount(value: &Value) -> u128 {
+    match value {
+        Value::String(s) => s.parse().unwrap_or(0),
+        // Parsed via the textual form rather than `as_u64`, so any integer
+        // serde_json holds exactly is accepted; a number it holds as a float
+        // (text with an exponent or fraction, e.g. `1e24`) does not parse and
+        // counts as 0.
+        Value::Number(n) => n.to_string().parse().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Effective voting weight of an account: its own `balance` plus any stake
+/// `delegated_balance` delegated to it. Both are summed because the ballot
+/// contract credits a voter with the stake they control directly and the stake
+/// others have delegated to them; the tally mirrors that weighting but remains
+/// advisory (the figures are caller-supplied, see [`ProposalTally`]).
+fn voting_power(v_account: &VAccount) -> u128 {
+    let VAccount::V0(account) = v_account;
+    parse_amount(&account.balance) + parse_amount(&account.delegated_balance)
+}
+
+/// The ballot-account identity a vote is cast for — the staked account the
+/// weight comes from, not the proxy agent that submitted the transaction.
+fn voter_account_id(v_account: &VAccount) -> AccountId {
+    let VAccount::V0(account) = v_account;
+    account.account_id.clone()
+}
 
 // Governance constants
-const GAS_FOR_GOVERNANCE: Gas = Gas::from_tgas(50);
 const GAS_FOR_CALLBACK: Gas = Gas::from_tgas(30);
-const DEPOSIT: NearToken = NearToken::from_millinear(1); // 0.001 NEAR
-const VOTING_CONTRACT: &str = "vote.ballotbox.testnet";
+
+// Attestation report layout: the 32-byte code measurement (the enclave's
+// codehash) occupies a fixed field at the head of the signed report, followed
+// by the report_data field that binds the quote to the registering account
+// (so a quote cannot be replayed by a different caller).
+const MEASUREMENT_OFFSET: usize = 0;
+const MEASUREMENT_LEN: usize = 32;
+const REPORT_DATA_OFFSET: usize = MEASUREMENT_OFFSET + MEASUREMENT_LEN;
 
 #[near(serializers = [json, borsh])]
 #[derive(Clone)]
@@ -20,12 +72,117 @@ pub struct Worker {
     codehash: String,
 }
 
+/// Quorum and threshold rules for a proposal, expressed as percentages and
+/// fixed the first time the proxy sees the proposal. Modeled on CosmWasm's
+/// `VotingRules`.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct VotingRules {
+    pub quorum: u8,
+    pub threshold: u8,
+}
+
+impl Default for VotingRules {
+    fn default() -> Self {
+        Self {
+            quorum: 0,
+            threshold: 50,
+        }
+    }
+}
+
+/// A single confirmed vote: who cast it, which option they chose, and the
+/// stake-derived weight it carried.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct VoteRecord {
+    pub voter: AccountId,
+    pub vote: u8,
+    pub weight: U128,
+}
+
+/// Running tally for one proposal. Only confirmed votes are recorded here;
+/// `option_weights` maps each option to its accumulated weight and `votes`
+/// preserves each voter's latest choice for auditing. `eligible_weight` is the
+/// electorate's total weight supplied when the proposal is first seen, against
+/// which quorum (turnout) is measured; it is `0` when the caller did not
+/// provide it, in which case quorum can only be satisfied by a `0` requirement.
+///
+/// The weights come from the caller-supplied `v_account` and are NOT verified
+/// against the backend or the merkle proof, so this tally is advisory for
+/// off-chain clients, not an authoritative vote count.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct ProposalTally {
+    pub rules: VotingRules,
+    pub eligible_weight: u128,
+    pub option_weights: std::collections::HashMap<u8, u128>,
+    pub votes: Vec<VoteRecord>,
+}
+
+/// Outcome of evaluating a proposal's tally against its rules.
+#[near(serializers = [json])]
+pub struct ProposalResult {
+    pub total_weight: U128,
+    pub leading_option: Option<u8>,
+    pub quorum_met: bool,
+    pub threshold_met: bool,
+}
+
+/// Resolution state of a single proposal within a batch. Each promise
+/// resolves independently, so an item stays `Pending` until its own callback
+/// fires; an item whose voter has no voting power is marked `Failed` at once
+/// and gets no callback.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, PartialEq)]
+pub enum BatchItemState {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// One proposal's slot in a batch cast.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct BatchItem {
+    pub proposal_id: ProposalId,
+    pub vote: u8,
+    pub state: BatchItemState,
+}
+
+/// Per-item outcome of a `cast_votes` call, so an agent can retry only the
+/// proposals that bounced.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct BatchStatus {
+    pub items: Vec<BatchItem>,
+}
+
+/// Per-backend routing configuration for a voting contract. Each registered
+/// backend carries the target `AccountId` together with the deposit and gas
+/// budget to attach when proxying a vote, so the same proxy can serve several
+/// ballot contracts instead of the old compile-time constant.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct VotingBackendConfig {
+    pub account_id: AccountId,
+    pub deposit: NearToken,
+    pub gas: Gas,
+}
+
 #[near(contract_state)]
 #[derive(PanicOnDefault)]
 pub struct Contract {
     pub owner_id: AccountId,
     pub approved_codehashes: IterableSet<String>,
     pub worker_by_account_id: IterableMap<AccountId, Worker>,
+    pub voting_backends: IterableMap<String, VotingBackendConfig>,
+    pub tallies: IterableMap<ProposalId, ProposalTally>,
+    pub batches: IterableMap<u64, BatchStatus>,
+    pub next_batch_id: u64,
+    /// Ed25519 public key of the attestation authority whose signature over a
+    /// quote the contract trusts. Registration is closed until it is set.
+    pub attestation_public_key: Option<Vec<u8>>,
 }
 
 #[near]
@@ -37,6 +194,11 @@ impl Contract {
             owner_id,
             approved_codehashes: IterableSet::new(b"a"),
             worker_by_account_id: IterableMap::new(b"b"),
+            voting_backends: IterableMap::new(b"c"),
+            tallies: IterableMap::new(b"d"),
+            batches: IterableMap::new(b"e"),
+            next_batch_id: 0,
+            attestation_public_key: None,
         }
     }
 
@@ -47,41 +209,214 @@ impl Contract {
         self.approved_codehashes.insert(codehash);
     }
 
-    pub fn register_agent(&mut self, codehash: String) -> bool {
-        // THIS IS A LOCAL DEV CONTRACT, SKIPPING ATTESTATION CHECKS
+    pub fn set_attestation_public_key(&mut self, public_key: Base64VecU8) {
+        self.require_owner();
+        require!(public_key.0.len() == 32, "ed25519 public key must be 32 bytes");
+        self.attestation_public_key = Some(public_key.0);
+    }
+
+    pub fn register_agent(&mut self, quote: AttestationQuote) -> bool {
+        // Verify the TEE quote before trusting the worker's claimed codehash.
+        let public_key = self
+            .attestation_public_key
+            .as_ref()
+            .expect("attestation public key not set");
+
+        let signature: [u8; 64] = quote
+            .signature
+            .0
+            .as_slice()
+            .try_into()
+            .expect("signature must be 64 bytes");
+        let public_key: [u8; 32] = public_key
+            .as_slice()
+            .try_into()
+            .expect("attestation public key must be 32 bytes");
+
+        require!(
+            env::ed25519_verify(&signature, &quote.report.0, &public_key),
+            "invalid attestation signature"
+        );
+
+        // The code measurement lives in a fixed field of the signed report; the
+        // bytes after it (report_data) vary per quote, so
+        // only the measurement field — not a hash of the whole blob — identifies
+        // the enclave code and must match the approved codehash.
+        let report = &quote.report.0;
+        require!(
+            report.len() >= MEASUREMENT_OFFSET + MEASUREMENT_LEN,
+            "attestation report too short"
+        );
+        let measurement = to_hex(&report[MEASUREMENT_OFFSET..MEASUREMENT_OFFSET + MEASUREMENT_LEN]);
+        require!(
+            measurement == quote.codehash,
+            "codehash does not match attestation measurement"
+        );
+        require!(
+            self.approved_codehashes.contains(&quote.codehash),
+            "codehash not approved"
+        );
 
         let predecessor = env::predecessor_account_id();
-        self.worker_by_account_id
-            .insert(predecessor, Worker { codehash });
+
+        // The report_data field binds the quote to the account it was issued
+        // for; require it to name the caller so a captured quote cannot be
+        // replayed to register a different account.
+        let report_data = &report[REPORT_DATA_OFFSET..];
+        let bound_account = std::str::from_utf8(report_data)
+            .ok()
+            .map(|s| s.trim_end_matches('\0'))
+            .and_then(|s| s.parse::<AccountId>().ok())
+            .expect("attestation report_data is not a valid account id");
+        require!(
+            bound_account == predecessor,
+            "attestation is not bound to the caller"
+        );
+
+        self.worker_by_account_id.insert(
+            predecessor,
+            Worker {
+                codehash: quote.codehash,
+            },
+        );
 
         true
     }
 
+    pub fn register_voting_backend(
+        &mut self,
+        backend_id: String,
+        account_id: AccountId,
+        deposit: NearToken,
+        gas: Gas,
+    ) {
+        self.require_owner();
+        self.voting_backends.insert(
+            backend_id,
+            VotingBackendConfig {
+                account_id,
+                deposit,
+                gas,
+            },
+        );
+    }
+
+    pub fn remove_voting_backend(&mut self, backend_id: String) {
+        self.require_owner();
+        self.voting_backends.remove(&backend_id);
+    }
+
     // Governance functions
 
     pub fn cast_vote(
     &mut self,
+    backend_id: String,
     proposal_id: ProposalId,
     vote: u8,
     merkle_proof: MerkleProof,
     v_account: VAccount,
+    voting_rules: Option<VotingRules>,
+    eligible_weight: Option<U128>,
 ) -> Promise {
-    env::log_str(&format!(
-        "🗳️ PROXY: Casting vote {} for proposal {}",
-        vote, proposal_id
-    ));
-
-    ext_voting::ext(VOTING_CONTRACT.parse().unwrap())
-        .with_static_gas(GAS_FOR_GOVERNANCE)
-        .with_attached_deposit(DEPOSIT)
+    self.require_approved_codehash();
+
+    let backend = self
+        .voting_backends
+        .get(&backend_id)
+        .expect("unknown voting backend")
+        .clone();
+
+    // Fix the rules and electorate size the first time we see this proposal.
+    self.ensure_tally(proposal_id, voting_rules, eligible_weight);
+
+    // Reject no-stake voters before spending gas/deposit on a doomed call.
+    let weight = voting_power(&v_account);
+    require!(weight > 0, "voter has no voting power");
+
+    // Identify the vote by the staked ballot account, not the proxy predecessor.
+    let voter = voter_account_id(&v_account);
+
+    events::vote_submitted(proposal_id, vote, &voter);
+
+    ext_voting::ext(backend.account_id)
+        .with_static_gas(backend.gas)
+        .with_attached_deposit(backend.deposit)
         .vote(proposal_id, vote, merkle_proof, v_account)
         .then(
             ext_self::ext(env::current_account_id())
                 .with_static_gas(GAS_FOR_CALLBACK)
-                .vote_callback(proposal_id, vote)
+                .vote_callback(proposal_id, vote, voter, U128(weight))
         )
 }
 
+    /// Fan out one voting promise per entry with voting power, each chained to
+    /// its own callback so the proposals resolve independently; entries with
+    /// no voting power are marked failed without a promise. Returns a batch id
+    /// whose status can be polled with `get_batch_status`.
+    pub fn cast_votes(&mut self, backend_id: String, votes: Vec<ProxyVoteArgs>) -> u64 {
+        self.require_approved_codehash();
+
+        let backend = self
+            .voting_backends
+            .get(&backend_id)
+            .expect("unknown voting backend")
+            .clone();
+
+        let batch_id = self.next_batch_id;
+        self.next_batch_id += 1;
+
+        let items = votes
+            .iter()
+            .map(|v| BatchItem {
+                proposal_id: v.proposal_id,
+                vote: v.vote,
+                state: BatchItemState::Pending,
+            })
+            .collect();
+        self.batches.insert(batch_id, BatchStatus { items });
+
+        for (index, args) in votes.into_iter().enumerate() {
+            let ProxyVoteArgs {
+                proposal_id,
+                vote,
+                merkle_proof,
+                v_account,
+            } = args;
+
+            let weight = voting_power(&v_account);
+            // Identify the vote by the staked ballot account, not the predecessor.
+            let voter = voter_account_id(&v_account);
+            if weight == 0 {
+                // No stake: fail this item up front without spending gas.
+                self.mark_batch_item(batch_id, index as u32, false);
+                events::vote_failed(proposal_id, vote, &voter, "voter has no voting power");
+                continue;
+            }
+
+            self.ensure_tally(proposal_id, None, None);
+            events::vote_submitted(proposal_id, vote, &voter);
+
+            ext_voting::ext(backend.account_id.clone())
+                .with_static_gas(backend.gas)
+                .with_attached_deposit(backend.deposit)
+                .vote(proposal_id, vote, merkle_proof, v_account)
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_static_gas(GAS_FOR_CALLBACK)
+                        .batch_vote_callback(
+                            batch_id,
+                            index as u32,
+                            proposal_id,
+                            vote,
+                            voter.clone(),
+                            U128(weight),
+                        ),
+                );
+        }
+
+        batch_id
+    }
+
     // View functions
 
     pub fn get_agent(&self, account_id: AccountId) -> Worker {
@@ -95,13 +430,145 @@ impl Contract {
         env::account_balance()
     }
 
+    /// All codehashes currently approved for attestation, for operator audit.
+    pub fn list_approved_codehashes(&self) -> Vec<String> {
+        self.approved_codehashes.iter().cloned().collect()
+    }
+
+    /// Every registered worker paired with its account, for operator audit.
+    pub fn get_registered_agents(&self) -> Vec<(AccountId, Worker)> {
+        self.worker_by_account_id
+            .iter()
+            .map(|(account_id, worker)| (account_id.clone(), worker.clone()))
+            .collect()
+    }
+
+    /// Effective voting weight of an account, for clients to check before
+    /// submitting a vote that would otherwise be rejected.
+    pub fn get_voting_power(&self, v_account: VAccount) -> U128 {
+        U128(voting_power(&v_account))
+    }
+
+    /// Per-proposal outcome of a batch cast, so an agent can retry only the
+    /// items that bounced.
+    pub fn get_batch_status(&self, batch_id: u64) -> BatchStatus {
+        self.batches
+            .get(&batch_id)
+            .expect("unknown batch")
+            .clone()
+    }
+
+    /// Each voter's latest confirmed vote for a proposal, in the order those
+    /// votes landed; a recast replaces the voter's earlier record.
+    pub fn get_proposal_votes(&self, proposal_id: ProposalId) -> Vec<VoteRecord> {
+        self.tallies
+            .get(&proposal_id)
+            .map(|t| t.votes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Evaluate a proposal's tally against its rules. `quorum_met` is turnout:
+    /// total cast weight as a percentage of the electorate's eligible weight.
+    /// `threshold_met` is the leading option's share of the cast weight. When
+    /// no eligible weight was recorded, quorum can only be met by a `0`
+    /// requirement.
+    pub fn get_proposal_result(&self, proposal_id: ProposalId) -> ProposalResult {
+        let tally = self.tallies.get(&proposal_id).expect("unknown proposal");
+
+        let total: u128 = tally.option_weights.values().copied().sum();
+        let (leading_option, leading_weight) = tally
+            .option_weights
+            .iter()
+            .max_by_key(|(_, w)| **w)
+            .map(|(opt, w)| (Some(*opt), *w))
+            .unwrap_or((None, 0));
+
+        let quorum_met = if tally.eligible_weight > 0 {
+            total * 100 >= tally.rules.quorum as u128 * tally.eligible_weight
+        } else {
+            tally.rules.quorum == 0
+        };
+        let threshold_met =
+            total > 0 && leading_weight * 100 >= tally.rules.threshold as u128 * total;
+
+        ProposalResult {
+            total_weight: U128(total),
+            leading_option,
+            quorum_met,
+            threshold_met,
+        }
+    }
+
+    // Internal helpers
+
+    /// Create a tally for a proposal the first time it is seen, fixing its
+    /// rules and the electorate's eligible weight.
+    fn ensure_tally(
+        &mut self,
+        proposal_id: ProposalId,
+        rules: Option<VotingRules>,
+        eligible_weight: Option<U128>,
+    ) {
+        if !self.tallies.contains_key(&proposal_id) {
+            self.tallies.insert(
+                proposal_id,
+                ProposalTally {
+                    rules: rules.unwrap_or_default(),
+                    eligible_weight: eligible_weight.map(|w| w.0).unwrap_or(0),
+                    option_weights: std::collections::HashMap::new(),
+                    votes: Vec::new(),
+                },
+            );
+        }
+    }
+
+    /// Record a confirmed vote against its proposal tally, aggregated by stake.
+    /// A voter casting again replaces their previous record rather than
+    /// double-counting, so a repeated `(proposal_id, voter)` cannot inflate the
+    /// tally.
+    fn record_confirmed_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        vote: u8,
+        voter: AccountId,
+        weight: U128,
+    ) {
+        if let Some(tally) = self.tallies.get_mut(&proposal_id) {
+            // Back out any prior vote from this voter before re-applying.
+            if let Some(prev) = tally.votes.iter().position(|r| r.voter == voter) {
+                let old = tally.votes.remove(prev);
+                if let Some(w) = tally.option_weights.get_mut(&old.vote) {
+                    *w = w.saturating_sub(old.weight.0);
+                }
+            }
+            *tally.option_weights.entry(vote).or_insert(0) += weight.0;
+            tally.votes.push(VoteRecord {
+                voter,
+                vote,
+                weight,
+            });
+        }
+    }
+
+    /// Flip one batch item to its terminal success/failure state.
+    fn mark_batch_item(&mut self, batch_id: u64, index: u32, succeeded: bool) {
+        if let Some(batch) = self.batches.get_mut(&batch_id) {
+            if let Some(item) = batch.items.get_mut(index as usize) {
+                item.state = if succeeded {
+                    BatchItemState::Succeeded
+                } else {
+                    BatchItemState::Failed
+                };
+            }
+        }
+    }
+
     // Access control helpers
 
     fn require_owner(&mut self) {
         require!(env::predecessor_account_id() == self.owner_id);
     }
 
-    #[allow(dead_code)]
     fn require_approved_codehash(&mut self) {
         let worker = self.get_agent(env::predecessor_account_id());
         require!(self.approved_codehashes.contains(&worker.codehash));
@@ -116,21 +583,165 @@ impl SelfCallbacks for Contract {
         &mut self,
         proposal_id: ProposalId,
         vote: u8,
+        voter: AccountId,
+        weight: U128,
+        #[callback_result] result: Result<(), PromiseError>,
+    ) {
+        match result {
+            Ok(_) => {
+                // Only confirmed votes count toward the tally, aggregated by stake.
+                self.record_confirmed_vote(proposal_id, vote, voter.clone(), weight);
+                events::vote_confirmed(proposal_id, vote, &voter);
+            }
+            Err(e) => {
+                events::vote_failed(proposal_id, vote, &voter, &format!("{:?}", e));
+            }
+        }
+    }
+
+    #[private]
+    fn batch_vote_callback(
+        &mut self,
+        batch_id: u64,
+        index: u32,
+        proposal_id: ProposalId,
+        vote: u8,
+        voter: AccountId,
+        weight: U128,
         #[callback_result] result: Result<(), PromiseError>,
     ) {
         match result {
             Ok(_) => {
-                env::log_str(&format!(
-                    "✅ PROXY: Successfully cast vote {} for proposal {}",
-                    vote, proposal_id
-                ));
+                self.record_confirmed_vote(proposal_id, vote, voter.clone(), weight);
+                self.mark_batch_item(batch_id, index, true);
+                events::vote_confirmed(proposal_id, vote, &voter);
             }
             Err(e) => {
-                env::log_str(&format!(
-                    "❌ PROXY: Failed to cast vote for proposal {}: {:?}",
-                    proposal_id, e
-                ));
+                self.mark_batch_item(batch_id, index, false);
+                events::vote_failed(proposal_id, vote, &voter, &format!("{:?}", e));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::serde_json::json;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+    use traits::Account;
+
+    fn context(predecessor: AccountId) {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .current_account_id(accounts(0))
+            .predecessor_account_id(predecessor);
+        testing_env!(builder.build());
+    }
+
+    fn v_account(id: AccountId, balance: Value, delegated_balance: Value) -> VAccount {
+        VAccount::V0(Account {
+            account_id: id,
+            update_timestamp: 0u64.into(),
+            balance,
+            delegated_balance,
+            delegation: None,
+        })
+    }
+
+    #[test]
+    fn parse_amount_handles_strings_numbers_and_large_values() {
+        assert_eq!(parse_amount(&json!("1000")), 1000);
+        assert_eq!(parse_amount(&json!(42)), 42);
+        // Above u64::MAX — must not coerce to zero.
+        let big = "340282366920938463463374607431768211455"; // u128::MAX
+        assert_eq!(parse_amount(&json!(big)), u128::MAX);
+        assert_eq!(parse_amount(&json!(20_000_000_000_000_000_000u128.to_string())), 20_000_000_000_000_000_000);
+        assert_eq!(parse_amount(&json!(null)), 0);
+    }
+
+    #[test]
+    fn voting_power_sums_balance_and_delegated() {
+        let account = v_account(accounts(1), json!("100"), json!("25"));
+        assert_eq!(voting_power(&account), 125);
+        assert_eq!(voter_account_id(&account), accounts(1));
+    }
+
+    #[test]
+    fn to_hex_encodes_lowercase() {
+        assert_eq!(to_hex(&[0x00, 0x0f, 0xff]), "000fff");
+    }
+
+    #[test]
+    fn tally_keys_on_ballot_account_not_predecessor() {
+        context(accounts(0));
+        let mut contract = Contract::init(accounts(0));
+        contract.ensure_tally(1, None, None);
+
+        // Two distinct ballot accounts, proxied by the same predecessor.
+        contract.record_confirmed_vote(1, 1, accounts(1), U128(30));
+        contract.record_confirmed_vote(1, 1, accounts(2), U128(40));
+
+        let votes = contract.get_proposal_votes(1);
+        assert_eq!(votes.len(), 2);
+        let result = contract.get_proposal_result(1);
+        assert_eq!(result.total_weight, U128(70));
+        assert_eq!(result.leading_option, Some(1));
+    }
+
+    #[test]
+    fn same_voter_recast_replaces_previous_record() {
+        context(accounts(0));
+        let mut contract = Contract::init(accounts(0));
+        contract.ensure_tally(1, None, None);
+
+        contract.record_confirmed_vote(1, 1, accounts(1), U128(30));
+        contract.record_confirmed_vote(1, 0, accounts(1), U128(30));
+
+        let votes = contract.get_proposal_votes(1);
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].vote, 0);
+        assert_eq!(contract.get_proposal_result(1).total_weight, U128(30));
+    }
+
+    #[test]
+    fn quorum_measures_turnout_against_eligible_weight() {
+        context(accounts(0));
+        let mut contract = Contract::init(accounts(0));
+
+        // 70 cast against an electorate of 100 with a 50% quorum/threshold.
+        contract.ensure_tally(
+            1,
+            Some(VotingRules {
+                quorum: 50,
+                threshold: 50,
+            }),
+            Some(U128(100)),
+        );
+        contract.record_confirmed_vote(1, 1, accounts(1), U128(70));
+        let result = contract.get_proposal_result(1);
+        assert!(result.quorum_met);
+        assert!(result.threshold_met);
+
+        // Same votes against a far larger electorate fail the quorum.
+        contract.ensure_tally(
+            2,
+            Some(VotingRules {
+                quorum: 50,
+                threshold: 50,
+            }),
+            Some(U128(1000)),
+        );
+        contract.record_confirmed_vote(2, 1, accounts(1), U128(70));
+        assert!(!contract.get_proposal_result(2).quorum_met);
+    }
+
+    #[test]
+    fn measurement_slice_matches_report_head() {
+        let mut report = vec![0xabu8; MEASUREMENT_LEN];
+        report.extend_from_slice(b"extra report_data");
+        let measurement = to_hex(&report[MEASUREMENT_OFFSET..MEASUREMENT_OFFSET + MEASUREMENT_LEN]);
+        assert_eq!(measurement, "ab".repeat(MEASUREMENT_LEN));
+    }
+}
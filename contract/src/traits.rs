@@ -1,6 +1,6 @@
 use near_sdk::{
     ext_contract,
-    json_types::{Base58CryptoHash, U64},
+    json_types::{Base58CryptoHash, Base64VecU8, U128, U64},
     serde::{Deserialize, Serialize},
     serde_json::Value,
     AccountId, Promise, PromiseError,
@@ -29,6 +29,19 @@ pub struct ProxyVoteArgs {
     pub v_account: VAccount,
 }
 
+/// A TEE attestation quote presented by a worker at registration: the raw
+/// report, its signature from the attestation authority, and the codehash the
+/// worker claims to be running. The contract reads the measurement field out of
+/// `report` and checks it matches `codehash` before trusting the claim.
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(crate = "near_sdk::serde")]
+#[schemars(crate = "schemars")]
+pub struct AttestationQuote {
+    pub report: Base64VecU8,
+    pub signature: Base64VecU8,
+    pub codehash: String,
+}
+
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(crate = "near_sdk::serde")]
 #[schemars(crate = "schemars")]
@@ -77,6 +90,19 @@ pub trait SelfCallbacks {
         &mut self,
         proposal_id: ProposalId,
         vote: u8,
+        voter: AccountId,
+        weight: U128,
+        #[callback_result] result: Result<(), PromiseError>,
+    );
+
+    fn batch_vote_callback(
+        &mut self,
+        batch_id: u64,
+        index: u32,
+        proposal_id: ProposalId,
+        vote: u8,
+        voter: AccountId,
+        weight: U128,
         #[callback_result] result: Result<(), PromiseError>,
     );
 }